@@ -0,0 +1,122 @@
+//! Runtime control over whether ANSI styling is emitted at all.
+//!
+//! By default this follows the environment: `CLICOLOR_FORCE` forces color on,
+//! `NO_COLOR` or `CLICOLOR=0` force it off, and otherwise color is emitted
+//! only when stdout looks like a terminal. [`set_override`] lets a caller
+//! pin the decision (e.g. from a `--color` flag) until [`unset_override`]
+//! is called.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+const AUTO: u8 = 0;
+const ALWAYS: u8 = 1;
+const NEVER: u8 = 2;
+
+static SHOULD_COLORIZE: AtomicU8 = AtomicU8::new(AUTO);
+
+/// Forces colorizing on or off, overriding environment/TTY detection until
+/// [`unset_override`] is called.
+pub fn set_override(enabled: bool) {
+    SHOULD_COLORIZE.store(if enabled { ALWAYS } else { NEVER }, Ordering::SeqCst);
+}
+
+/// Clears a manual override set by [`set_override`], returning to automatic
+/// detection via [`from_env`].
+pub fn unset_override() {
+    SHOULD_COLORIZE.store(AUTO, Ordering::SeqCst);
+}
+
+/// Resolves whether color should be emitted by inspecting the environment:
+///
+/// 1. `CLICOLOR_FORCE` set to a nonzero value forces color on.
+/// 2. `NO_COLOR` set to anything forces color off.
+/// 3. `CLICOLOR=0` forces color off.
+/// 4. Otherwise, color is on only when stdout is a terminal (requires the
+///    `tty` feature; without it this falls back to `true`).
+pub fn from_env() -> bool {
+    if env_nonzero("CLICOLOR_FORCE") {
+        return true;
+    }
+
+    if std::env::var_os("NO_COLOR").is_some() {
+        return false;
+    }
+
+    if std::env::var("CLICOLOR").map(|v| v == "0").unwrap_or(false) {
+        return false;
+    }
+
+    stdout_is_tty()
+}
+
+fn env_nonzero(key: &str) -> bool {
+    match std::env::var(key) {
+        Ok(v) => v != "0" && !v.is_empty(),
+        Err(_) => false,
+    }
+}
+
+#[cfg(feature = "tty")]
+fn stdout_is_tty() -> bool {
+    use std::io::IsTerminal;
+    std::io::stdout().is_terminal()
+}
+
+#[cfg(not(feature = "tty"))]
+fn stdout_is_tty() -> bool {
+    true
+}
+
+/// Returns whether `ColoredString` should currently emit ANSI codes,
+/// honoring any manual override before falling back to `from_env`.
+pub(crate) fn should_colorize() -> bool {
+    match SHOULD_COLORIZE.load(Ordering::SeqCst) {
+        ALWAYS => true,
+        NEVER => false,
+        _ => from_env(),
+    }
+}
+
+// `SHOULD_COLORIZE` is a single process-wide atomic, so any test that
+// overrides it (directly or via `force_colorize_for_test`) must hold this
+// lock for the duration of the override — otherwise tests running on other
+// threads observe a flickering value and become flaky.
+#[cfg(test)]
+static TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Forces color on for the life of the returned guard, serialized against
+/// every other test that touches the global override, and restores `Auto`
+/// when the guard drops. Tests that assert on ANSI output should hold this
+/// guard instead of relying on the ambient environment/TTY, which is almost
+/// never a terminal under `cargo test`.
+#[cfg(test)]
+pub(crate) fn force_colorize_for_test() -> ColorOverrideGuard {
+    let guard = TEST_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    set_override(true);
+    ColorOverrideGuard(guard)
+}
+
+#[cfg(test)]
+pub(crate) struct ColorOverrideGuard(#[allow(dead_code)] std::sync::MutexGuard<'static, ()>);
+
+#[cfg(test)]
+impl Drop for ColorOverrideGuard {
+    fn drop(&mut self) {
+        unset_override();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn override_wins_over_env() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        set_override(true);
+        assert!(should_colorize());
+        set_override(false);
+        assert!(!should_colorize());
+        unset_override();
+    }
+}
@@ -1,7 +1,12 @@
 use std::fmt;
+use std::str::FromStr;
 
-// Standard text colors
-#[derive(Debug, Clone, Copy)]
+pub mod control;
+
+// Standard text colors, plus the fixed xterm 256-color palette and 24-bit
+// truecolor, unified behind a single type so callers can pass any color
+// kind to `fg`/`bg` uniformly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Color {
     Black,
     Red,
@@ -19,6 +24,10 @@ pub enum Color {
     BrightMagenta,
     BrightCyan,
     BrightWhite,
+    /// A fixed xterm 256-color (8-bit) palette index.
+    Ansi256(u8),
+    /// A 24-bit truecolor value.
+    Rgb(RgbColor),
 }
 
 //  RGB color with 24-bit color depth (16.7 million colors)
@@ -35,74 +44,161 @@ impl RgbColor {
     }
 }
 
+/// The reason a string could not be parsed as a [`Color`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError(String);
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "'{}' is not a valid color", self.0)
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
 impl Color {
-    //  ANSI color code
-    // foreground color
-    fn fg_code(&self) -> u8 {
+    // Writes the ANSI foreground code(s) for this color directly to `w`,
+    // with no intermediate `String` allocation.
+    fn write_ansi_fg(&self, w: &mut impl fmt::Write) -> fmt::Result {
         match self {
-            Color::Black => 30,
-            Color::Red => 31,
-            Color::Green => 32,
-            Color::Yellow => 33,
-            Color::Blue => 34,
-            Color::Magenta => 35,
-            Color::Cyan => 36,
-            Color::White => 37,
-            Color::BrightBlack => 90,
-            Color::BrightRed => 91,
-            Color::BrightGreen => 92,
-            Color::BrightYellow => 93,
-            Color::BrightBlue => 94,
-            Color::BrightMagenta => 95,
-            Color::BrightCyan => 96,
-            Color::BrightWhite => 97,
+            Color::Black => w.write_str("30"),
+            Color::Red => w.write_str("31"),
+            Color::Green => w.write_str("32"),
+            Color::Yellow => w.write_str("33"),
+            Color::Blue => w.write_str("34"),
+            Color::Magenta => w.write_str("35"),
+            Color::Cyan => w.write_str("36"),
+            Color::White => w.write_str("37"),
+            Color::BrightBlack => w.write_str("90"),
+            Color::BrightRed => w.write_str("91"),
+            Color::BrightGreen => w.write_str("92"),
+            Color::BrightYellow => w.write_str("93"),
+            Color::BrightBlue => w.write_str("94"),
+            Color::BrightMagenta => w.write_str("95"),
+            Color::BrightCyan => w.write_str("96"),
+            Color::BrightWhite => w.write_str("97"),
+            Color::Ansi256(n) => write!(w, "38;5;{}", n),
+            Color::Rgb(rgb) => write!(w, "38;2;{};{};{}", rgb.r, rgb.g, rgb.b),
         }
     }
 
-    // background color
-    fn bg_code(&self) -> u8 {
+    // Writes the ANSI background code(s) for this color directly to `w`.
+    fn write_ansi_bg(&self, w: &mut impl fmt::Write) -> fmt::Result {
         match self {
-            Color::Black => 40,
-            Color::Red => 41,
-            Color::Green => 42,
-            Color::Yellow => 43,
-            Color::Blue => 44,
-            Color::Magenta => 45,
-            Color::Cyan => 46,
-            Color::White => 47,
-            Color::BrightBlack => 100,
-            Color::BrightRed => 101,
-            Color::BrightGreen => 102,
-            Color::BrightYellow => 103,
-            Color::BrightBlue => 104,
-            Color::BrightMagenta => 105,
-            Color::BrightCyan => 106,
-            Color::BrightWhite => 107,
+            Color::Black => w.write_str("40"),
+            Color::Red => w.write_str("41"),
+            Color::Green => w.write_str("42"),
+            Color::Yellow => w.write_str("43"),
+            Color::Blue => w.write_str("44"),
+            Color::Magenta => w.write_str("45"),
+            Color::Cyan => w.write_str("46"),
+            Color::White => w.write_str("47"),
+            Color::BrightBlack => w.write_str("100"),
+            Color::BrightRed => w.write_str("101"),
+            Color::BrightGreen => w.write_str("102"),
+            Color::BrightYellow => w.write_str("103"),
+            Color::BrightBlue => w.write_str("104"),
+            Color::BrightMagenta => w.write_str("105"),
+            Color::BrightCyan => w.write_str("106"),
+            Color::BrightWhite => w.write_str("107"),
+            Color::Ansi256(n) => write!(w, "48;5;{}", n),
+            Color::Rgb(rgb) => write!(w, "48;2;{};{};{}", rgb.r, rgb.g, rgb.b),
         }
     }
 }
 
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parses names like `"blue"`, `"bright red"`, `"#ff8800"`, `"38"`
+    /// (an `Ansi256` index), and `"grey50"`/`"gray50"` (also `Ansi256`).
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let s = src.trim();
+
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(|| ParseColorError(src.to_string()));
+        }
+
+        if let Ok(n) = s.parse::<u8>() {
+            return Ok(Color::Ansi256(n));
+        }
+
+        let lower = s.to_ascii_lowercase();
+        for prefix in ["grey", "gray"] {
+            if let Some(rest) = lower.strip_prefix(prefix) {
+                if let Ok(n) = rest.parse::<u8>() {
+                    return Ok(Color::Ansi256(n));
+                }
+            }
+        }
+
+        match lower.replace(['_', '-'], " ").as_str() {
+            "black" => Ok(Color::Black),
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "yellow" => Ok(Color::Yellow),
+            "blue" => Ok(Color::Blue),
+            "magenta" | "purple" => Ok(Color::Magenta),
+            "cyan" => Ok(Color::Cyan),
+            "white" => Ok(Color::White),
+            "bright black" => Ok(Color::BrightBlack),
+            "bright red" => Ok(Color::BrightRed),
+            "bright green" => Ok(Color::BrightGreen),
+            "bright yellow" => Ok(Color::BrightYellow),
+            "bright blue" => Ok(Color::BrightBlue),
+            "bright magenta" | "bright purple" => Ok(Color::BrightMagenta),
+            "bright cyan" => Ok(Color::BrightCyan),
+            "bright white" => Ok(Color::BrightWhite),
+            _ => Err(ParseColorError(src.to_string())),
+        }
+    }
+}
+
+/// Infallible conversion used by [`Colorize::color`]/[`Colorize::on_color`]
+/// so unrecognized strings degrade to `Color::White` rather than panicking.
+impl From<&str> for Color {
+    fn from(s: &str) -> Self {
+        s.parse().unwrap_or(Color::White)
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some(Color::Rgb(RgbColor::new(r, g, b)))
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
-    fg_color: Option<Color>,
-    bg_color: Option<Color>,
-    fg_rgb_color: Option<RgbColor>,
-    bg_rgb_color: Option<RgbColor>,
+    fg: Option<Color>,
+    bg: Option<Color>,
     bold: bool,
+    dimmed: bool,
     italic: bool,
     underline: bool,
+    blink: bool,
+    reverse: bool,
+    hidden: bool,
+    strikethrough: bool,
 }
 
 impl Default for Style {
     fn default() -> Self {
         Style {
-            fg_color: None,
-            bg_color: None,
-            fg_rgb_color: None,
-            bg_rgb_color: None,
+            fg: None,
+            bg: None,
             bold: false,
+            dimmed: false,
             italic: false,
             underline: false,
+            blink: false,
+            reverse: false,
+            hidden: false,
+            strikethrough: false,
         }
     }
 }
@@ -112,29 +208,39 @@ impl Style {
         Style::default()
     }
 
-    // note: look into a better implementation
     pub fn fg(mut self, color: Color) -> Self {
-        self.fg_color = Some(color);
-        self.fg_rgb_color = None; // Clear RGB color when setting standard color
+        self.fg = Some(color);
         self
     }
 
     pub fn bg(mut self, color: Color) -> Self {
-        self.bg_color = Some(color);
-        self.bg_rgb_color = None;
+        self.bg = Some(color);
         self
     }
 
     // New methods for RGB colors
     pub fn fg_rgb(mut self, r: u8, g: u8, b: u8) -> Self {
-        self.fg_rgb_color = Some(RgbColor::new(r, g, b));
-        self.fg_color = None; // Clear standard color when setting RGB color
+        self.fg = Some(Color::Rgb(RgbColor::new(r, g, b)));
         self
     }
 
     pub fn bg_rgb(mut self, r: u8, g: u8, b: u8) -> Self {
-        self.bg_rgb_color = Some(RgbColor::new(r, g, b));
-        self.bg_color = None;
+        self.bg = Some(Color::Rgb(RgbColor::new(r, g, b)));
+        self
+    }
+
+    /// Sets the foreground color from the fixed xterm 256-color (8-bit)
+    /// palette: the 16 standard colors, a 216-color cube, and a 24-step
+    /// grayscale ramp.
+    pub fn fg_fixed(mut self, color: u8) -> Self {
+        self.fg = Some(Color::Ansi256(color));
+        self
+    }
+
+    /// Sets the background color from the fixed xterm 256-color (8-bit)
+    /// palette.
+    pub fn bg_fixed(mut self, color: u8) -> Self {
+        self.bg = Some(Color::Ansi256(color));
         self
     }
 
@@ -143,6 +249,11 @@ impl Style {
         self
     }
 
+    pub fn dimmed(mut self) -> Self {
+        self.dimmed = true;
+        self
+    }
+
     pub fn italic(mut self) -> Self {
         self.italic = true;
         self
@@ -153,50 +264,111 @@ impl Style {
         self
     }
 
-    fn format_prefix(&self) -> String {
-        let mut codes = Vec::new();
+    pub fn blink(mut self) -> Self {
+        self.blink = true;
+        self
+    }
 
-        // Standard foreground color
-        if let Some(fg) = self.fg_color {
-            codes.push(fg.fg_code().to_string());
-        }
+    pub fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
 
-        // RGB foreground color
-        if let Some(rgb) = self.fg_rgb_color {
-            codes.push(format!("38;2;{};{};{}", rgb.r, rgb.g, rgb.b));
-        }
+    pub fn reversed(self) -> Self {
+        self.reverse()
+    }
+
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    pub fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// True when this style carries no color or attributes, i.e. rendering
+    /// it would emit no ANSI codes at all.
+    fn is_plain(&self) -> bool {
+        self.fg.is_none()
+            && self.bg.is_none()
+            && !self.bold
+            && !self.dimmed
+            && !self.italic
+            && !self.underline
+            && !self.blink
+            && !self.reverse
+            && !self.hidden
+            && !self.strikethrough
+    }
 
-        // Standard background color
-        if let Some(bg) = self.bg_color {
-            codes.push(bg.bg_code().to_string());
+    /// Writes the `\x1b[...m` SGR prefix directly to `w`, without building
+    /// an intermediate `String`. Writes nothing if the style is plain.
+    #[allow(unused_assignments)]
+    pub fn write_prefix(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        if self.is_plain() {
+            return Ok(());
         }
 
-        // RGB background color
-        if let Some(rgb) = self.bg_rgb_color {
-            codes.push(format!("48;2;{};{};{}", rgb.r, rgb.g, rgb.b));
+        w.write_str("\x1b[")?;
+        let mut wrote_any = false;
+
+        macro_rules! write_code {
+            ($($arg:tt)*) => {{
+                if wrote_any {
+                    w.write_char(';')?;
+                }
+                write!(w, $($arg)*)?;
+                wrote_any = true;
+            }};
         }
 
+        if let Some(fg) = &self.fg {
+            if wrote_any {
+                w.write_char(';')?;
+            }
+            fg.write_ansi_fg(w)?;
+            wrote_any = true;
+        }
+        if let Some(bg) = &self.bg {
+            if wrote_any {
+                w.write_char(';')?;
+            }
+            bg.write_ansi_bg(w)?;
+            wrote_any = true;
+        }
         if self.bold {
-            codes.push("1".to_string());
+            write_code!("1");
+        }
+        if self.dimmed {
+            write_code!("2");
         }
-
         if self.italic {
-            codes.push("3".to_string());
+            write_code!("3");
         }
-
         if self.underline {
-            codes.push("4".to_string());
+            write_code!("4");
         }
-
-        if codes.is_empty() {
-            return String::new();
+        if self.blink {
+            write_code!("5");
+        }
+        if self.reverse {
+            write_code!("7");
+        }
+        if self.hidden {
+            write_code!("8");
+        }
+        if self.strikethrough {
+            write_code!("9");
         }
 
-        format!("\x1b[{}m", codes.join(";"))
+        w.write_char('m')
     }
 
-    fn format_suffix() -> &'static str {
-        "\x1b[0m"
+    /// Writes the SGR reset sequence directly to `w`.
+    pub fn write_suffix(&self, w: &mut impl fmt::Write) -> fmt::Result {
+        w.write_str("\x1b[0m")
     }
 
     pub fn paint<T: AsRef<str>>(&self, text: T) -> ColoredString {
@@ -213,13 +385,105 @@ pub struct ColoredString {
     style: Style,
 }
 
+impl ColoredString {
+    /// Streams the styled text to `w` (e.g. `Stdout::lock()`), writing the
+    /// prefix, text, and suffix directly instead of formatting into a
+    /// `String` first. Useful in hot loops and log pipelines.
+    pub fn write_to(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        if !control::should_colorize() || self.style.is_plain() {
+            return w.write_all(self.text.as_bytes());
+        }
+
+        let mut prefix = StackBuf::new();
+        self.style
+            .write_prefix(&mut prefix)
+            .map_err(|_| std::io::Error::other("style prefix too long"))?;
+
+        w.write_all(prefix.as_bytes())?;
+        w.write_all(self.text.as_bytes())?;
+        w.write_all(b"\x1b[0m")
+    }
+}
+
+/// A small fixed-capacity `fmt::Write` sink backed by a stack array, used to
+/// render a `Style` prefix without a heap allocation before handing the
+/// bytes to an `io::Write`. 80 bytes comfortably covers the worst case: two
+/// truecolor codes plus every boolean attribute.
+struct StackBuf {
+    buf: [u8; 80],
+    len: usize,
+}
+
+impl StackBuf {
+    fn new() -> Self {
+        StackBuf { buf: [0; 80], len: 0 }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl fmt::Write for StackBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let bytes = s.as_bytes();
+        if self.len + bytes.len() > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+        Ok(())
+    }
+}
+
 impl fmt::Display for ColoredString {
+    // Padding/alignment/precision must apply to the *visible* text, not the
+    // escape-sequence-wrapped one, or `format!("{:>10}", "x".red())` pads by
+    // the wrong length. So we compute the adjusted visible text ourselves
+    // and write it directly, instead of deferring to `f.pad`.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let prefix = self.style.format_prefix();
-        if prefix.is_empty() {
-            write!(f, "{}", self.text)
-        } else {
-            write!(f, "{}{}{}", prefix, self.text, Style::format_suffix())
+        let visible = pad_visible_text(&self.text, f);
+
+        if !control::should_colorize() || self.style.is_plain() {
+            return f.write_str(&visible);
+        }
+
+        self.style.write_prefix(f)?;
+        f.write_str(&visible)?;
+        self.style.write_suffix(f)
+    }
+}
+
+fn pad_visible_text(text: &str, f: &fmt::Formatter) -> String {
+    let truncated = match f.precision() {
+        Some(precision) => text.chars().take(precision).collect::<String>(),
+        None => text.to_string(),
+    };
+
+    let width = match f.width() {
+        Some(width) => width,
+        None => return truncated,
+    };
+
+    let len = truncated.chars().count();
+    if len >= width {
+        return truncated;
+    }
+
+    let fill = f.fill();
+    let diff = width - len;
+    match f.align().unwrap_or(fmt::Alignment::Left) {
+        fmt::Alignment::Left => format!("{}{}", truncated, fill.to_string().repeat(diff)),
+        fmt::Alignment::Right => format!("{}{}", fill.to_string().repeat(diff), truncated),
+        fmt::Alignment::Center => {
+            let left = diff / 2;
+            let right = diff - left;
+            format!(
+                "{}{}{}",
+                fill.to_string().repeat(left),
+                truncated,
+                fill.to_string().repeat(right)
+            )
         }
     }
 }
@@ -266,6 +530,15 @@ pub fn on_rgb<T: AsRef<str>>(r: u8, g: u8, b: u8, text: T) -> ColoredString {
     Style::new().bg_rgb(r, g, b).paint(text)
 }
 
+// Fixed (8-bit) color helper functions
+pub fn fixed<T: AsRef<str>>(color: u8, text: T) -> ColoredString {
+    Style::new().fg_fixed(color).paint(text)
+}
+
+pub fn on_fixed<T: AsRef<str>>(color: u8, text: T) -> ColoredString {
+    Style::new().bg_fixed(color).paint(text)
+}
+
 pub trait Colorize {
     fn red(&self) -> ColoredString;
     fn green(&self) -> ColoredString;
@@ -276,16 +549,51 @@ pub trait Colorize {
     fn white(&self) -> ColoredString;
     fn black(&self) -> ColoredString;
     fn bold(&self) -> ColoredString;
+    fn dimmed(&self) -> ColoredString;
     fn italic(&self) -> ColoredString;
     fn underline(&self) -> ColoredString;
-    fn color(&self, color: Color) -> ColoredString;
-    fn bg_color(&self, color: Color) -> ColoredString;
-    
+    fn blink(&self) -> ColoredString;
+    fn reverse(&self) -> ColoredString;
+    fn reversed(&self) -> ColoredString;
+    fn hidden(&self) -> ColoredString;
+    fn strikethrough(&self) -> ColoredString;
+    /// Sets the foreground color from anything convertible to [`Color`],
+    /// including `Color` itself and color names like `"blue"`/`"#ff8800"`.
+    ///
+    /// An unrecognized string (e.g. a typo'd config value) silently falls
+    /// back to `Color::White` via [`Color`]'s `From<&str>` impl rather than
+    /// returning an error; parse with `str::parse::<Color>` directly if you
+    /// need to detect and report bad input.
+    fn color<S: Into<Color>>(&self, color: S) -> ColoredString;
+    /// Sets the background color from anything convertible to [`Color`].
+    /// See [`Colorize::color`] for the unrecognized-string fallback behavior.
+    fn on_color<S: Into<Color>>(&self, color: S) -> ColoredString;
+    fn on_black(&self) -> ColoredString;
+    fn on_red(&self) -> ColoredString;
+    fn on_green(&self) -> ColoredString;
+    fn on_yellow(&self) -> ColoredString;
+    fn on_blue(&self) -> ColoredString;
+    fn on_magenta(&self) -> ColoredString;
+    fn on_cyan(&self) -> ColoredString;
+    fn on_white(&self) -> ColoredString;
+
     fn rgb(&self, r: u8, g: u8, b: u8) -> ColoredString;
     fn on_rgb(&self, r: u8, g: u8, b: u8) -> ColoredString;
+
+    fn fixed(&self, color: u8) -> ColoredString;
+    fn on_fixed(&self, color: u8) -> ColoredString;
+
+    /// Resets the accumulated style back to the default (no color, no
+    /// attributes), keeping the text.
+    fn clear(&self) -> ColoredString;
+    /// Alias for [`Colorize::clear`].
+    fn normal(&self) -> ColoredString;
 }
 
-impl<T: AsRef<str>> Colorize for T {
+// Implemented directly on `str` (rather than generically over
+// `AsRef<str>`) so that `ColoredString` can have its own impl below that
+// merges into the accumulated style instead of starting from scratch.
+impl Colorize for str {
     fn red(&self) -> ColoredString {
         red(self)
     }
@@ -322,6 +630,10 @@ impl<T: AsRef<str>> Colorize for T {
         Style::new().bold().paint(self)
     }
 
+    fn dimmed(&self) -> ColoredString {
+        Style::new().dimmed().paint(self)
+    }
+
     fn italic(&self) -> ColoredString {
         Style::new().italic().paint(self)
     }
@@ -330,21 +642,360 @@ impl<T: AsRef<str>> Colorize for T {
         Style::new().underline().paint(self)
     }
 
-    fn color(&self, color: Color) -> ColoredString {
-        Style::new().fg(color).paint(self)
+    fn blink(&self) -> ColoredString {
+        Style::new().blink().paint(self)
+    }
+
+    fn reverse(&self) -> ColoredString {
+        Style::new().reverse().paint(self)
+    }
+
+    fn reversed(&self) -> ColoredString {
+        Style::new().reversed().paint(self)
+    }
+
+    fn hidden(&self) -> ColoredString {
+        Style::new().hidden().paint(self)
+    }
+
+    fn strikethrough(&self) -> ColoredString {
+        Style::new().strikethrough().paint(self)
+    }
+
+    fn color<S: Into<Color>>(&self, color: S) -> ColoredString {
+        Style::new().fg(color.into()).paint(self)
+    }
+
+    fn on_color<S: Into<Color>>(&self, color: S) -> ColoredString {
+        Style::new().bg(color.into()).paint(self)
+    }
+
+    fn on_black(&self) -> ColoredString {
+        Style::new().bg(Color::Black).paint(self)
+    }
+
+    fn on_red(&self) -> ColoredString {
+        Style::new().bg(Color::Red).paint(self)
     }
 
-    fn bg_color(&self, color: Color) -> ColoredString {
-        Style::new().bg(color).paint(self)
+    fn on_green(&self) -> ColoredString {
+        Style::new().bg(Color::Green).paint(self)
     }
-    
+
+    fn on_yellow(&self) -> ColoredString {
+        Style::new().bg(Color::Yellow).paint(self)
+    }
+
+    fn on_blue(&self) -> ColoredString {
+        Style::new().bg(Color::Blue).paint(self)
+    }
+
+    fn on_magenta(&self) -> ColoredString {
+        Style::new().bg(Color::Magenta).paint(self)
+    }
+
+    fn on_cyan(&self) -> ColoredString {
+        Style::new().bg(Color::Cyan).paint(self)
+    }
+
+    fn on_white(&self) -> ColoredString {
+        Style::new().bg(Color::White).paint(self)
+    }
+
     fn rgb(&self, r: u8, g: u8, b: u8) -> ColoredString {
         Style::new().fg_rgb(r, g, b).paint(self)
     }
-    
+
     fn on_rgb(&self, r: u8, g: u8, b: u8) -> ColoredString {
         Style::new().bg_rgb(r, g, b).paint(self)
     }
+
+    fn fixed(&self, color: u8) -> ColoredString {
+        Style::new().fg_fixed(color).paint(self)
+    }
+
+    fn on_fixed(&self, color: u8) -> ColoredString {
+        Style::new().bg_fixed(color).paint(self)
+    }
+
+    fn clear(&self) -> ColoredString {
+        Style::new().paint(self)
+    }
+
+    fn normal(&self) -> ColoredString {
+        self.clear()
+    }
+}
+
+impl Colorize for String {
+    fn red(&self) -> ColoredString {
+        self.as_str().red()
+    }
+
+    fn green(&self) -> ColoredString {
+        self.as_str().green()
+    }
+
+    fn blue(&self) -> ColoredString {
+        self.as_str().blue()
+    }
+
+    fn yellow(&self) -> ColoredString {
+        self.as_str().yellow()
+    }
+
+    fn magenta(&self) -> ColoredString {
+        self.as_str().magenta()
+    }
+
+    fn cyan(&self) -> ColoredString {
+        self.as_str().cyan()
+    }
+
+    fn white(&self) -> ColoredString {
+        self.as_str().white()
+    }
+
+    fn black(&self) -> ColoredString {
+        self.as_str().black()
+    }
+
+    fn bold(&self) -> ColoredString {
+        self.as_str().bold()
+    }
+
+    fn dimmed(&self) -> ColoredString {
+        self.as_str().dimmed()
+    }
+
+    fn italic(&self) -> ColoredString {
+        self.as_str().italic()
+    }
+
+    fn underline(&self) -> ColoredString {
+        self.as_str().underline()
+    }
+
+    fn blink(&self) -> ColoredString {
+        self.as_str().blink()
+    }
+
+    fn reverse(&self) -> ColoredString {
+        self.as_str().reverse()
+    }
+
+    fn reversed(&self) -> ColoredString {
+        self.as_str().reversed()
+    }
+
+    fn hidden(&self) -> ColoredString {
+        self.as_str().hidden()
+    }
+
+    fn strikethrough(&self) -> ColoredString {
+        self.as_str().strikethrough()
+    }
+
+    fn color<S: Into<Color>>(&self, color: S) -> ColoredString {
+        self.as_str().color(color)
+    }
+
+    fn on_color<S: Into<Color>>(&self, color: S) -> ColoredString {
+        self.as_str().on_color(color)
+    }
+
+    fn on_black(&self) -> ColoredString {
+        self.as_str().on_black()
+    }
+
+    fn on_red(&self) -> ColoredString {
+        self.as_str().on_red()
+    }
+
+    fn on_green(&self) -> ColoredString {
+        self.as_str().on_green()
+    }
+
+    fn on_yellow(&self) -> ColoredString {
+        self.as_str().on_yellow()
+    }
+
+    fn on_blue(&self) -> ColoredString {
+        self.as_str().on_blue()
+    }
+
+    fn on_magenta(&self) -> ColoredString {
+        self.as_str().on_magenta()
+    }
+
+    fn on_cyan(&self) -> ColoredString {
+        self.as_str().on_cyan()
+    }
+
+    fn on_white(&self) -> ColoredString {
+        self.as_str().on_white()
+    }
+
+    fn rgb(&self, r: u8, g: u8, b: u8) -> ColoredString {
+        self.as_str().rgb(r, g, b)
+    }
+
+    fn on_rgb(&self, r: u8, g: u8, b: u8) -> ColoredString {
+        self.as_str().on_rgb(r, g, b)
+    }
+
+    fn fixed(&self, color: u8) -> ColoredString {
+        self.as_str().fixed(color)
+    }
+
+    fn on_fixed(&self, color: u8) -> ColoredString {
+        self.as_str().on_fixed(color)
+    }
+
+    fn clear(&self) -> ColoredString {
+        self.as_str().clear()
+    }
+
+    fn normal(&self) -> ColoredString {
+        self.as_str().normal()
+    }
+}
+
+// Each method here merges into `self.style` rather than starting from
+// `Style::default()`, so chained calls like `"x".red().bold()` compose
+// instead of the later call discarding earlier styling.
+impl Colorize for ColoredString {
+    fn red(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.fg(Color::Red) }
+    }
+
+    fn green(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.fg(Color::Green) }
+    }
+
+    fn blue(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.fg(Color::Blue) }
+    }
+
+    fn yellow(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.fg(Color::Yellow) }
+    }
+
+    fn magenta(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.fg(Color::Magenta) }
+    }
+
+    fn cyan(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.fg(Color::Cyan) }
+    }
+
+    fn white(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.fg(Color::White) }
+    }
+
+    fn black(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.fg(Color::Black) }
+    }
+
+    fn bold(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bold() }
+    }
+
+    fn dimmed(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.dimmed() }
+    }
+
+    fn italic(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.italic() }
+    }
+
+    fn underline(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.underline() }
+    }
+
+    fn blink(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.blink() }
+    }
+
+    fn reverse(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.reverse() }
+    }
+
+    fn reversed(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.reversed() }
+    }
+
+    fn hidden(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.hidden() }
+    }
+
+    fn strikethrough(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.strikethrough() }
+    }
+
+    fn color<S: Into<Color>>(&self, color: S) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.fg(color.into()) }
+    }
+
+    fn on_color<S: Into<Color>>(&self, color: S) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bg(color.into()) }
+    }
+
+    fn on_black(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bg(Color::Black) }
+    }
+
+    fn on_red(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bg(Color::Red) }
+    }
+
+    fn on_green(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bg(Color::Green) }
+    }
+
+    fn on_yellow(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bg(Color::Yellow) }
+    }
+
+    fn on_blue(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bg(Color::Blue) }
+    }
+
+    fn on_magenta(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bg(Color::Magenta) }
+    }
+
+    fn on_cyan(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bg(Color::Cyan) }
+    }
+
+    fn on_white(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bg(Color::White) }
+    }
+
+    fn rgb(&self, r: u8, g: u8, b: u8) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.fg_rgb(r, g, b) }
+    }
+
+    fn on_rgb(&self, r: u8, g: u8, b: u8) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bg_rgb(r, g, b) }
+    }
+
+    fn fixed(&self, color: u8) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.fg_fixed(color) }
+    }
+
+    fn on_fixed(&self, color: u8) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: self.style.bg_fixed(color) }
+    }
+
+    fn clear(&self) -> ColoredString {
+        ColoredString { text: self.text.clone(), style: Style::default() }
+    }
+
+    fn normal(&self) -> ColoredString {
+        self.clear()
+    }
 }
 
 impl AsRef<str> for ColoredString {
@@ -359,18 +1010,124 @@ mod tests {
 
     #[test]
     fn test_red_text() {
+        let _guard = control::force_colorize_for_test();
         let colored = red("This is red text");
         assert_eq!(colored.to_string(), "\x1b[31mThis is red text\x1b[0m");
     }
 
+    #[test]
+    fn test_chained_calls_compose() {
+        let _guard = control::force_colorize_for_test();
+        let colored = "this is red on blue".red().on_blue();
+        assert_eq!(colored.to_string(), "\x1b[31;44mthis is red on blue\x1b[0m");
+    }
+
+    #[test]
+    fn test_chained_calls_compose_attributes() {
+        let _guard = control::force_colorize_for_test();
+        let colored = "warn".yellow().bold().underline();
+        assert_eq!(colored.to_string(), "\x1b[33;1;4mwarn\x1b[0m");
+    }
+
+    #[test]
+    fn test_width_pads_visible_text() {
+        let _guard = control::force_colorize_for_test();
+        let colored = format!("{:>10}", "hi".blue());
+        assert_eq!(colored, "\x1b[34m        hi\x1b[0m");
+    }
+
+    #[test]
+    fn test_width_with_fill_and_center() {
+        let _guard = control::force_colorize_for_test();
+        let colored = format!("{:*^6}", "hi".red());
+        assert_eq!(colored, "\x1b[31m**hi**\x1b[0m");
+    }
+
+    #[test]
+    fn test_precision_truncates_visible_text() {
+        let _guard = control::force_colorize_for_test();
+        let colored = format!("{:.3}", "hello".green());
+        assert_eq!(colored, "\x1b[32mhel\x1b[0m");
+    }
+
+    #[test]
+    fn test_clear_resets_style() {
+        let colored = "reset me".red().bold().clear();
+        assert_eq!(colored.to_string(), "reset me");
+    }
+
+    #[test]
+    fn test_color_from_str_names() {
+        assert_eq!("blue".parse::<Color>().unwrap(), Color::Blue);
+        assert_eq!("bright red".parse::<Color>().unwrap(), Color::BrightRed);
+        assert_eq!("Bright_Red".parse::<Color>().unwrap(), Color::BrightRed);
+    }
+
+    #[test]
+    fn test_color_from_str_hex_and_ansi256() {
+        assert_eq!(
+            "#ff8800".parse::<Color>().unwrap(),
+            Color::Rgb(RgbColor::new(0xff, 0x88, 0x00))
+        );
+        assert_eq!("38".parse::<Color>().unwrap(), Color::Ansi256(38));
+        assert_eq!("grey50".parse::<Color>().unwrap(), Color::Ansi256(50));
+    }
+
+    #[test]
+    fn test_color_from_str_invalid() {
+        assert!("not-a-color".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_color_from_str_hex_non_ascii_does_not_panic() {
+        assert!("#1中23".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_colorize_color_from_str_name() {
+        let _guard = control::force_colorize_for_test();
+        let colored = "warn".color("yellow").on_color("#000000");
+        assert_eq!(colored.to_string(), "\x1b[33;48;2;0;0;0mwarn\x1b[0m");
+    }
+
+    #[test]
+    fn test_write_prefix_matches_display() {
+        let style = Style::new().fg(Color::Red).bold();
+        let mut prefix = String::new();
+        style.write_prefix(&mut prefix).unwrap();
+        assert_eq!(prefix, "\x1b[31;1m");
+
+        let mut suffix = String::new();
+        style.write_suffix(&mut suffix).unwrap();
+        assert_eq!(suffix, "\x1b[0m");
+    }
+
+    #[test]
+    fn test_write_prefix_plain_style_is_empty() {
+        let mut prefix = String::new();
+        Style::new().write_prefix(&mut prefix).unwrap();
+        assert!(prefix.is_empty());
+    }
+
+    #[test]
+    fn test_colored_string_write_to() {
+        let _guard = control::force_colorize_for_test();
+        let colored = "stream me".green().bold();
+        let mut buf = Vec::new();
+        colored.write_to(&mut buf).unwrap();
+        assert_eq!(buf, b"\x1b[32;1mstream me\x1b[0m");
+    }
+
     #[test]
     fn test_colorize_trait() {
+        let _guard = control::force_colorize_for_test();
         let colored = "Blue text".blue();
         assert_eq!(colored.to_string(), "\x1b[34mBlue text\x1b[0m");
     }
 
     #[test]
     fn test_combined_styles() {
+        let _guard = control::force_colorize_for_test();
         let styled = Style::new()
             .fg(Color::Green)
             .bg(Color::Black)
@@ -381,27 +1138,68 @@ mod tests {
             "\x1b[32;40;1mBold green text on black background\x1b[0m"
         );
     }
-    
+
     #[test]
     fn test_rgb_color() {
+        let _guard = control::force_colorize_for_test();
         let colored = rgb(255, 100, 50, "RGB text");
         assert_eq!(colored.to_string(), "\x1b[38;2;255;100;50mRGB text\x1b[0m");
     }
-    
+
     #[test]
     fn test_rgb_trait_method() {
+        let _guard = control::force_colorize_for_test();
         let colored = "RGB trait".rgb(50, 100, 255);
         assert_eq!(colored.to_string(), "\x1b[38;2;50;100;255mRGB trait\x1b[0m");
     }
-    
+
     #[test]
     fn test_bg_rgb_color() {
+        let _guard = control::force_colorize_for_test();
         let colored = on_rgb(50, 100, 255, "Background RGB");
         assert_eq!(colored.to_string(), "\x1b[48;2;50;100;255mBackground RGB\x1b[0m");
     }
-    
+
+    #[test]
+    fn test_fixed_color() {
+        let _guard = control::force_colorize_for_test();
+        let colored = fixed(208, "Fixed color text");
+        assert_eq!(colored.to_string(), "\x1b[38;5;208mFixed color text\x1b[0m");
+    }
+
+    #[test]
+    fn test_fixed_trait_method() {
+        let _guard = control::force_colorize_for_test();
+        let colored = "Fixed trait".fixed(27);
+        assert_eq!(colored.to_string(), "\x1b[38;5;27mFixed trait\x1b[0m");
+    }
+
+    #[test]
+    fn test_on_fixed_color() {
+        let _guard = control::force_colorize_for_test();
+        let colored = on_fixed(208, "Background fixed");
+        assert_eq!(colored.to_string(), "\x1b[48;5;208mBackground fixed\x1b[0m");
+    }
+
+    #[test]
+    fn test_dimmed_and_strikethrough() {
+        let _guard = control::force_colorize_for_test();
+        let styled = Style::new().dimmed().strikethrough().paint("Struck out");
+        assert_eq!(styled.to_string(), "\x1b[2;9mStruck out\x1b[0m");
+    }
+
+    #[test]
+    fn test_blink_reverse_hidden_trait_methods() {
+        let _guard = control::force_colorize_for_test();
+        assert_eq!("x".blink().to_string(), "\x1b[5mx\x1b[0m");
+        assert_eq!("x".reverse().to_string(), "\x1b[7mx\x1b[0m");
+        assert_eq!("x".reversed().to_string(), "\x1b[7mx\x1b[0m");
+        assert_eq!("x".hidden().to_string(), "\x1b[8mx\x1b[0m");
+    }
+
     #[test]
     fn test_complex_rgb_styling() {
+        let _guard = control::force_colorize_for_test();
         let styled = Style::new()
             .fg_rgb(255, 50, 50)
             .bg_rgb(20, 20, 50)